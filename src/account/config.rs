@@ -2,7 +2,11 @@
 //!
 //! Module dedicated to account configuration.
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 #[cfg(feature = "imap")]
@@ -29,12 +33,32 @@ pub struct AccountConfig {
     /// this one will be used by default.
     pub default: Option<bool>,
 
+    /// The sync-direction preset for this account.
+    ///
+    /// Expands into a [`FolderSyncPermissions`], [`FlagSyncPermissions`]
+    /// and [`MessageSyncPermissions`] set for the left and right
+    /// backends in [`BackendGlobalConfig::into_account_config`].
+    /// Explicit per-backend permission blocks still override the
+    /// preset, except where they directly contradict it (e.g. the
+    /// preset requires a backend to be read-only but the explicit
+    /// permissions grant writes), which is reported as a config
+    /// error.
+    #[serde(default)]
+    pub mode: SyncMode,
+
     /// The account configuration dedicated to folders.
     pub folder: Option<FolderConfig>,
 
     /// The account configuration dedicated to envelopes.
     pub envelope: Option<EnvelopeConfig>,
 
+    /// The continuous watch/daemon sync configuration.
+    ///
+    /// Only parsed and stored: no watch mode exists in this crate,
+    /// so setting it does not change how the account is synced. See
+    /// [`WatchConfig`].
+    pub watch: Option<WatchConfig>,
+
     /// The configuration of the left backend.
     ///
     /// The left backend can be seen as the source backend, except
@@ -50,11 +74,118 @@ pub struct AccountConfig {
     pub right: BackendGlobalConfig,
 }
 
+/// A sync-direction preset, expanding into the fine-grained folder,
+/// flag and message sync permissions.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SyncMode {
+    /// Both backends may create, update and delete on either side.
+    /// This is the default, and matches the permissions used when no
+    /// `mode` is set.
+    #[default]
+    TwoWay,
+
+    /// The left backend is the source of truth: the right backend is
+    /// brought in line with it, but changes on the right never flow
+    /// back to the left.
+    MirrorLeftToRight,
+
+    /// The right backend is the source of truth: the left backend is
+    /// brought in line with it, but changes on the left never flow
+    /// back to the right.
+    MirrorRightToLeft,
+
+    /// Alias for [`SyncMode::MirrorLeftToRight`], for the common case
+    /// of treating the right backend as a read-only backup of the
+    /// left one.
+    Backup,
+}
+
+/// Which side of the sync a [`BackendGlobalConfig`] is being turned
+/// into an account config for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl SyncMode {
+    /// Whether this preset forbids the sync engine from writing to
+    /// `side` at all.
+    fn is_read_only(self, side: Side) -> bool {
+        match (self, side) {
+            (SyncMode::TwoWay, _) => false,
+            (SyncMode::MirrorLeftToRight | SyncMode::Backup, Side::Left) => true,
+            (SyncMode::MirrorLeftToRight | SyncMode::Backup, Side::Right) => false,
+            (SyncMode::MirrorRightToLeft, Side::Right) => true,
+            (SyncMode::MirrorRightToLeft, Side::Left) => false,
+        }
+    }
+
+    fn folder_permissions(self, side: Side) -> FolderSyncPermissions {
+        if self.is_read_only(side) {
+            FolderSyncPermissions {
+                create: false,
+                delete: false,
+                ..Default::default()
+            }
+        } else {
+            Default::default()
+        }
+    }
+
+    fn flag_permissions(self, side: Side) -> FlagSyncPermissions {
+        if self.is_read_only(side) {
+            FlagSyncPermissions {
+                update: false,
+                ..Default::default()
+            }
+        } else {
+            Default::default()
+        }
+    }
+
+    fn message_permissions(self, side: Side) -> MessageSyncPermissions {
+        if self.is_read_only(side) {
+            MessageSyncPermissions {
+                create: false,
+                update: false,
+                delete: false,
+                ..Default::default()
+            }
+        } else {
+            Default::default()
+        }
+    }
+
+    /// Whether an explicit `permissions` block on `side` contradicts
+    /// this preset, i.e. `side` is required to be read-only but
+    /// `permissions` grants a write.
+    fn folder_permissions_conflict(self, side: Side, permissions: FolderSyncPermissions) -> bool {
+        self.is_read_only(side) && (permissions.create || permissions.delete)
+    }
+
+    /// Like [`Self::folder_permissions_conflict`], for flag
+    /// permissions.
+    fn flag_permissions_conflict(self, side: Side, permissions: FlagSyncPermissions) -> bool {
+        self.is_read_only(side) && permissions.update
+    }
+
+    /// Like [`Self::folder_permissions_conflict`], for message
+    /// permissions.
+    fn message_permissions_conflict(self, side: Side, permissions: MessageSyncPermissions) -> bool {
+        self.is_read_only(side) && (permissions.create || permissions.update || permissions.delete)
+    }
+}
+
 impl AccountConfig {
     /// Configure the current account configuration.
     ///
     /// This function is mostly used to replace undefined keyring
-    /// entries by default ones, based on the given account name.
+    /// entries by default ones, based on the given account name. For
+    /// IMAP backends configured with OAuth 2.0, it also runs the
+    /// authorization code with PKCE flow on first use and silently
+    /// refreshes an expired access token before connecting.
     pub fn configure(&mut self, account_name: &str) -> Result<()> {
         match &mut self.left.backend {
             #[cfg(feature = "imap")]
@@ -62,6 +193,7 @@ impl AccountConfig {
                 config
                     .auth
                     .replace_undefined_keyring_entries(&account_name)?;
+                configure_imap_oauth2(config, account_name)?;
             }
             _ => (),
         }
@@ -72,6 +204,7 @@ impl AccountConfig {
                 config
                     .auth
                     .replace_undefined_keyring_entries(&account_name)?;
+                configure_imap_oauth2(config, account_name)?;
             }
             _ => (),
         }
@@ -80,11 +213,129 @@ impl AccountConfig {
     }
 }
 
+/// Run the OAuth 2.0 authorization code with PKCE flow on first use,
+/// or refresh an expired access token using the stored refresh token.
+///
+/// Does nothing for IMAP backends configured with password auth.
+///
+/// [`AccountConfig::configure`] calls this for both `left` and
+/// `right`, right after `replace_undefined_keyring_entries`, which
+/// gives every undefined keyring entry of `config.auth` (the OAuth 2.0
+/// client secret and tokens included) a default name based on the
+/// account name. Neither step reads a secret value itself.
+#[cfg(feature = "imap")]
+fn configure_imap_oauth2(config: &mut ImapConfig, account_name: &str) -> Result<()> {
+    let email::imap::config::ImapAuthConfig::OAuth2(oauth2_config) = &mut config.auth else {
+        return Ok(());
+    };
+
+    if !oauth2_config.is_configured() {
+        oauth2_config.configure(account_name)?;
+    } else if oauth2_config.access_token_is_expired()? {
+        oauth2_config.refresh_access_token()?;
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct FolderConfig {
     #[serde(default)]
     pub filter: FolderSyncStrategy,
+
+    /// The folder name mapping between the left and right backends.
+    ///
+    /// Maps a left folder name to its right counterpart, for setups
+    /// where the two backends do not share identical folder names
+    /// (e.g. `Sent` on one side, `[Gmail]/Sent Mail` on the other).
+    /// `INBOX` is never remapped, see [`FolderAliases`]. The mapping
+    /// is bijective: it is applied after the [`FolderSyncStrategy`]
+    /// filter has selected which folders to synchronize, and must be
+    /// invertible so that a left-to-right lookup followed by its
+    /// inverse recovers the original name.
+    #[serde(default)]
+    pub aliases: Option<HashMap<String, String>>,
+}
+
+impl FolderConfig {
+    /// Build the [`FolderAliases`] bijection from the configured
+    /// `aliases` map, if any.
+    ///
+    /// Errors if the map is not bijective (two left names aliasing to
+    /// the same right name, or vice versa).
+    pub fn aliases(&self) -> Result<FolderAliases> {
+        self.aliases
+            .as_ref()
+            .map(FolderAliases::from_map)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+/// A bijective folder name mapping between the left and right
+/// backends.
+///
+/// Built from [`FolderConfig::aliases`], this is applied after the
+/// [`FolderSyncStrategy`] filter has selected the folders to
+/// synchronize. The special `INBOX` folder is always treated
+/// case-insensitively and never remapped, matching IMAP's own
+/// handling of the mailbox.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FolderAliases {
+    left_to_right: HashMap<String, String>,
+    right_to_left: HashMap<String, String>,
+}
+
+impl FolderAliases {
+    /// Build the bijection from a left-to-right alias map.
+    ///
+    /// Errors if `aliases` is not bijective, i.e. if two left names
+    /// map to the same right name (or vice versa), since that would
+    /// make the mapping impossible to invert.
+    fn from_map(aliases: &HashMap<String, String>) -> Result<Self> {
+        let left_to_right = aliases.clone();
+        let right_to_left: HashMap<String, String> = aliases
+            .iter()
+            .map(|(left, right)| (right.clone(), left.clone()))
+            .collect();
+
+        if right_to_left.len() != left_to_right.len() {
+            anyhow::bail!(
+                "folder.aliases is not bijective: two left names alias to the same right \
+                 name, or two right names alias to the same left name"
+            );
+        }
+
+        Ok(Self {
+            left_to_right,
+            right_to_left,
+        })
+    }
+
+    /// Map a left folder name to its right counterpart.
+    pub fn to_right<'a>(&'a self, left_name: &'a str) -> &'a str {
+        if left_name.eq_ignore_ascii_case("INBOX") {
+            return left_name;
+        }
+
+        self.left_to_right
+            .get(left_name)
+            .map(String::as_str)
+            .unwrap_or(left_name)
+    }
+
+    /// Map a right folder name to its left counterpart.
+    pub fn to_left<'a>(&'a self, right_name: &'a str) -> &'a str {
+        if right_name.eq_ignore_ascii_case("INBOX") {
+            return right_name;
+        }
+
+        self.right_to_left
+            .get(right_name)
+            .map(String::as_str)
+            .unwrap_or(right_name)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -94,6 +345,105 @@ pub struct EnvelopeConfig {
     pub filter: EnvelopeSyncFilters,
 }
 
+/// Configuration for the continuous watch/daemon sync mode.
+///
+/// This crate holds the config shape and [`ChangeDebouncer`], which
+/// coalesces folder change events over `debounce_secs` and reports
+/// the folders whose debounce window has elapsed. Nothing else reads
+/// this config: there is no IMAP IDLE loop, no Maildir/Notmuch
+/// filesystem watcher, no reconnect-with-backoff for a dropped IDLE
+/// session, and no code that starts a sync from the folders
+/// [`ChangeDebouncer::drain_ready`] returns.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct WatchConfig {
+    /// How long to wait for more changes on a folder before
+    /// triggering an incremental sync of it, in seconds.
+    #[serde(default = "WatchConfig::default_debounce_secs")]
+    pub debounce_secs: u64,
+
+    /// The folders to watch. `None` stands for every folder selected
+    /// by the `folder.filter` strategy.
+    #[serde(default)]
+    pub folders: Option<Vec<String>>,
+}
+
+impl WatchConfig {
+    fn default_debounce_secs() -> u64 {
+        5
+    }
+}
+
+/// A change to one folder on one backend, to be coalesced and
+/// debounced by a [`ChangeDebouncer`]. Nothing in this crate
+/// produces these events.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FolderChangeEvent {
+    pub side: Side,
+    pub folder: String,
+}
+
+/// Debounces and coalesces [`FolderChangeEvent`]s for the watch/daemon
+/// sync mode described by [`WatchConfig`].
+///
+/// Events are keyed on the left-hand folder name, canonicalized
+/// through [`FolderAliases`]: a change reported for a right-side
+/// folder is first mapped back to its left counterpart via
+/// [`FolderAliases::to_left`], so a left change to `Sent` and a right
+/// change to its alias `[Gmail]/Sent Mail` are recognized as the same
+/// logical folder rather than queuing two redundant syncs. A folder
+/// seen changing is queued with the instant it was last observed;
+/// [`Self::drain_ready`] returns, and forgets, every folder whose
+/// debounce window has since elapsed. Recording a further change for
+/// a folder that is already queued simply bumps its timestamp, so
+/// repeated or simultaneous left/right changes to the same folder
+/// coalesce into a single pending sync instead of triggering one
+/// redundant pass per event.
+#[derive(Debug)]
+pub struct ChangeDebouncer {
+    debounce: Duration,
+    pending: HashMap<String, Instant>,
+}
+
+impl ChangeDebouncer {
+    pub fn new(config: &WatchConfig) -> Self {
+        Self {
+            debounce: Duration::from_secs(config.debounce_secs),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Record that `event` was observed at `now`, canonicalizing its
+    /// folder name to the left side through `aliases` so that the
+    /// same logical folder coalesces regardless of which side
+    /// reported the change.
+    pub fn record(&mut self, event: FolderChangeEvent, aliases: &FolderAliases, now: Instant) {
+        let canonical = match event.side {
+            Side::Left => event.folder,
+            Side::Right => aliases.to_left(&event.folder).to_string(),
+        };
+        self.pending.insert(canonical, now);
+    }
+
+    /// Return, and stop tracking, the left-hand name of every queued
+    /// folder whose debounce window has elapsed as of `now`.
+    pub fn drain_ready(&mut self, now: Instant) -> Vec<String> {
+        let debounce = self.debounce;
+        let ready: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, &last_seen)| now.duration_since(last_seen) >= debounce)
+            .map(|(folder, _)| folder.clone())
+            .collect();
+
+        for folder in &ready {
+            self.pending.remove(folder);
+        }
+
+        ready
+    }
+}
+
 /// The global backend configuration (left or right).
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
@@ -112,20 +462,81 @@ pub struct BackendGlobalConfig {
 }
 
 impl BackendGlobalConfig {
+    /// Turn this backend-specific configuration into an
+    /// [`email::account::config::AccountConfig`], ready to be used
+    /// by the sync engine.
+    ///
+    /// `mode` and `side` expand into the folder, flag and message
+    /// sync permissions to use by default; explicit per-backend
+    /// permission blocks override them, except where they directly
+    /// contradict the preset (e.g. `mode` requires `side` to be
+    /// read-only but the explicit permissions grant writes), which
+    /// is reported as an error.
+    ///
+    /// `folder_aliases` is returned alongside the account config
+    /// rather than folded into it, since it must be applied by the
+    /// sync engine *after* `folder_filter` has selected the folders
+    /// to synchronize.
     pub fn into_account_config(
         self,
         name: String,
+        mode: SyncMode,
+        side: Side,
         folder_filter: FolderSyncStrategy,
+        folder_aliases: FolderAliases,
         envelope_filter: EnvelopeSyncFilters,
-    ) -> (BackendConfig, Arc<email::account::config::AccountConfig>) {
-        (
+    ) -> Result<(
+        BackendConfig,
+        Arc<email::account::config::AccountConfig>,
+        FolderAliases,
+    )> {
+        let folder_permissions = match self.folder.map(|c| c.permissions) {
+            Some(permissions) => {
+                if mode.folder_permissions_conflict(side, permissions) {
+                    anyhow::bail!(
+                        "folder permissions for the {side:?} backend conflict with the \
+                         {mode:?} sync mode, which requires it to be read-only"
+                    );
+                }
+                permissions
+            }
+            None => mode.folder_permissions(side),
+        };
+
+        let flag_permissions = match self.flag.map(|c| c.permissions) {
+            Some(permissions) => {
+                if mode.flag_permissions_conflict(side, permissions) {
+                    anyhow::bail!(
+                        "flag permissions for the {side:?} backend conflict with the \
+                         {mode:?} sync mode, which requires it to be read-only"
+                    );
+                }
+                permissions
+            }
+            None => mode.flag_permissions(side),
+        };
+
+        let message_permissions = match self.message.map(|c| c.permissions) {
+            Some(permissions) => {
+                if mode.message_permissions_conflict(side, permissions) {
+                    anyhow::bail!(
+                        "message permissions for the {side:?} backend conflict with the \
+                         {mode:?} sync mode, which requires it to be read-only"
+                    );
+                }
+                permissions
+            }
+            None => mode.message_permissions(side),
+        };
+
+        Ok((
             self.backend,
             Arc::new(email::account::config::AccountConfig {
                 name,
                 folder: Some(email::folder::config::FolderConfig {
                     sync: Some(email::folder::sync::config::FolderSyncConfig {
                         filter: folder_filter,
-                        permissions: self.folder.map(|c| c.permissions).unwrap_or_default(),
+                        permissions: folder_permissions,
                     }),
                     ..Default::default()
                 }),
@@ -137,19 +548,20 @@ impl BackendGlobalConfig {
                 }),
                 flag: Some(email::flag::config::FlagConfig {
                     sync: Some(email::flag::sync::config::FlagSyncConfig {
-                        permissions: self.flag.map(|c| c.permissions).unwrap_or_default(),
+                        permissions: flag_permissions,
                     }),
                     ..Default::default()
                 }),
                 message: Some(email::message::config::MessageConfig {
                     sync: Some(email::message::sync::config::MessageSyncConfig {
-                        permissions: self.message.map(|c| c.permissions).unwrap_or_default(),
+                        permissions: message_permissions,
                     }),
                     ..Default::default()
                 }),
                 ..Default::default()
             }),
-        )
+            folder_aliases,
+        ))
     }
 }
 
@@ -192,4 +604,211 @@ pub struct FlagBackendConfig {
 pub struct MessageBackendConfig {
     #[serde(default)]
     pub permissions: MessageSyncPermissions,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folder_aliases_round_trip() {
+        let mut aliases = HashMap::new();
+        aliases.insert("Sent".to_string(), "[Gmail]/Sent Mail".to_string());
+        aliases.insert("Archive".to_string(), "[Gmail]/All Mail".to_string());
+
+        let aliases = FolderAliases::from_map(&aliases).unwrap();
+
+        assert_eq!(aliases.to_right("Sent"), "[Gmail]/Sent Mail");
+        assert_eq!(aliases.to_left(aliases.to_right("Sent")), "Sent");
+        assert_eq!(aliases.to_left("[Gmail]/All Mail"), "Archive");
+        assert_eq!(aliases.to_right(aliases.to_left("[Gmail]/All Mail")), "[Gmail]/All Mail");
+    }
+
+    #[test]
+    fn folder_aliases_inbox_is_never_remapped() {
+        let mut aliases = HashMap::new();
+        aliases.insert("INBOX".to_string(), "Inbox".to_string());
+
+        let aliases = FolderAliases::from_map(&aliases).unwrap();
+
+        assert_eq!(aliases.to_right("INBOX"), "INBOX");
+        assert_eq!(aliases.to_left("inbox"), "inbox");
+    }
+
+    #[test]
+    fn folder_aliases_rejects_non_bijective_map() {
+        let mut aliases = HashMap::new();
+        aliases.insert("Sent".to_string(), "Archive".to_string());
+        aliases.insert("Drafts".to_string(), "Archive".to_string());
+
+        assert!(FolderAliases::from_map(&aliases).is_err());
+    }
+
+    #[test]
+    fn backup_mode_is_read_only_on_the_source_side_only() {
+        assert!(SyncMode::Backup.is_read_only(Side::Left));
+        assert!(!SyncMode::Backup.is_read_only(Side::Right));
+    }
+
+    #[test]
+    fn two_way_mode_never_conflicts() {
+        let permissions = FolderSyncPermissions {
+            create: true,
+            delete: true,
+            ..Default::default()
+        };
+
+        assert!(!SyncMode::TwoWay.folder_permissions_conflict(Side::Left, permissions));
+        assert!(!SyncMode::TwoWay.folder_permissions_conflict(Side::Right, permissions));
+    }
+
+    #[test]
+    fn backup_mode_conflicts_only_when_permissions_grant_writes() {
+        let read_only = FolderSyncPermissions {
+            create: false,
+            delete: false,
+            ..Default::default()
+        };
+        let writable = FolderSyncPermissions {
+            create: true,
+            delete: false,
+            ..Default::default()
+        };
+
+        // A permissions block that grants no write, like the
+        // preset's own read-only default, must not be flagged as a
+        // conflict.
+        assert!(!SyncMode::Backup.folder_permissions_conflict(Side::Left, read_only));
+
+        // A permissions block that grants a write on a side the
+        // preset requires read-only is a genuine conflict.
+        assert!(SyncMode::Backup.folder_permissions_conflict(Side::Left, writable));
+
+        // The same writable permissions are fine on the non-read-only
+        // side.
+        assert!(!SyncMode::Backup.folder_permissions_conflict(Side::Right, writable));
+    }
+
+    #[test]
+    fn backup_mode_flag_and_message_conflicts_check_writes_only() {
+        let update_only = FlagSyncPermissions {
+            update: true,
+            ..Default::default()
+        };
+        assert!(SyncMode::Backup.flag_permissions_conflict(Side::Left, update_only));
+
+        let delete_only = MessageSyncPermissions {
+            create: false,
+            update: false,
+            delete: true,
+            ..Default::default()
+        };
+        assert!(SyncMode::Backup.message_permissions_conflict(Side::Left, delete_only));
+        assert!(!SyncMode::Backup.message_permissions_conflict(Side::Right, delete_only));
+    }
+
+    #[test]
+    fn change_debouncer_waits_for_the_debounce_window() {
+        let mut debouncer = ChangeDebouncer::new(&WatchConfig {
+            debounce_secs: 5,
+            folders: None,
+        });
+        let aliases = FolderAliases::default();
+        let seen_at = Instant::now();
+
+        debouncer.record(
+            FolderChangeEvent {
+                side: Side::Left,
+                folder: "INBOX".to_string(),
+            },
+            &aliases,
+            seen_at,
+        );
+
+        assert!(debouncer
+            .drain_ready(seen_at + Duration::from_secs(1))
+            .is_empty());
+        assert_eq!(
+            debouncer.drain_ready(seen_at + Duration::from_secs(5)),
+            vec!["INBOX".to_string()]
+        );
+        // Already drained: a later poll with no new events is empty.
+        assert!(debouncer
+            .drain_ready(seen_at + Duration::from_secs(10))
+            .is_empty());
+    }
+
+    #[test]
+    fn change_debouncer_coalesces_repeated_and_cross_side_events() {
+        let mut debouncer = ChangeDebouncer::new(&WatchConfig {
+            debounce_secs: 5,
+            folders: None,
+        });
+        let aliases = FolderAliases::default();
+        let t0 = Instant::now();
+
+        debouncer.record(
+            FolderChangeEvent {
+                side: Side::Left,
+                folder: "INBOX".to_string(),
+            },
+            &aliases,
+            t0,
+        );
+        // A change on the right for the same folder, a moment later,
+        // must not produce a second queued sync.
+        debouncer.record(
+            FolderChangeEvent {
+                side: Side::Right,
+                folder: "INBOX".to_string(),
+            },
+            &aliases,
+            t0 + Duration::from_secs(2),
+        );
+
+        // The debounce window restarted on the second event, so it is
+        // not ready 5s after the first one.
+        assert!(debouncer
+            .drain_ready(t0 + Duration::from_secs(5))
+            .is_empty());
+
+        let ready = debouncer.drain_ready(t0 + Duration::from_secs(7));
+        assert_eq!(ready, vec!["INBOX".to_string()]);
+    }
+
+    #[test]
+    fn change_debouncer_coalesces_aliased_cross_side_events() {
+        let mut aliases_map = HashMap::new();
+        aliases_map.insert("Sent".to_string(), "[Gmail]/Sent Mail".to_string());
+        let aliases = FolderAliases::from_map(&aliases_map).unwrap();
+
+        let mut debouncer = ChangeDebouncer::new(&WatchConfig {
+            debounce_secs: 5,
+            folders: None,
+        });
+        let t0 = Instant::now();
+
+        // A left change to "Sent" and a right change to its alias
+        // "[Gmail]/Sent Mail" are the same logical folder and must
+        // coalesce into a single pending sync, keyed on the left name.
+        debouncer.record(
+            FolderChangeEvent {
+                side: Side::Left,
+                folder: "Sent".to_string(),
+            },
+            &aliases,
+            t0,
+        );
+        debouncer.record(
+            FolderChangeEvent {
+                side: Side::Right,
+                folder: "[Gmail]/Sent Mail".to_string(),
+            },
+            &aliases,
+            t0 + Duration::from_secs(1),
+        );
+
+        let ready = debouncer.drain_ready(t0 + Duration::from_secs(6));
+        assert_eq!(ready, vec!["Sent".to_string()]);
+    }
+}